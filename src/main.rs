@@ -1,27 +1,127 @@
-use std::collections::{HashMap, VecDeque};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use rand::seq::SliceRandom;
+use rayon::prelude::*;
+
+// Header for the binary graph cache written by Graph::save_binary
+const CACHE_MAGIC: &[u8; 4] = b"CGBC"; // Congress Graph Binary Cache
+const CACHE_VERSION: u8 = 1;
+
+// Classification returned by Graph::is_eulerian
+#[derive(Debug, PartialEq, Eq)]
+enum EulerKind {
+    Circuit, // every vertex has even degree: the graph can be traced as a closed loop
+    Path,    // exactly two vertices have odd degree: traceable start-to-finish, not closed
+    Neither,
+}
+
+// Wraps f64 so it can sit inside a BinaryHeap, which needs Ord.
+// Congress edge weights are never NaN, so total_cmp is safe here.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OrderedF64(f64);
+
+impl Eq for OrderedF64 {}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
 
 #[derive(Debug)]
 struct Graph {
-    adj_list: HashMap<usize, Vec<usize>>,
+    adj_list: HashMap<usize, Vec<(usize, f64)>>,
+    labels: HashMap<usize, String>,
 }
 
 impl Graph {
     fn new() -> Self {
         Self {
             adj_list: HashMap::new(),
+            labels: HashMap::new(),
         }
     }
 
-    // Adds an edge (only unweighted)
-    fn add_edge(&mut self, source: usize, target: usize) {
-        self.adj_list.entry(source).or_insert_with(Vec::new).push(target);
+    // Load an edgelist plus a parallel CSV/TSV file mapping node id -> label
+    // (member name, handle, state, ...), so reporting can show names instead of bare ids
+    fn with_labels(edgelist_path: &str, labels_path: &str) -> Self {
+        let mut graph = Self::from_edgelist(edgelist_path);
+        graph.labels = Self::load_labels(labels_path);
+        graph
+    }
+
+    // Parse "id,label" (or tab-separated) lines into a node id -> label map
+    fn load_labels(path: &str) -> HashMap<usize, String> {
+        let file = File::open(path).expect("Unable to open labels file");
+        let reader = BufReader::new(file);
+
+        let mut labels = HashMap::new();
+
+        for line in reader.lines() {
+            let line = line.expect("Unable to read line");
+            let parts: Vec<&str> = line.splitn(2, [',', '\t']).collect();
+            if parts.len() < 2 {
+                continue;
+            }
+            if let Ok(id) = parts[0].trim().parse::<usize>() {
+                labels.insert(id, parts[1].trim().to_string());
+            }
+        }
+
+        labels
+    }
+
+    // Human-readable label for a node, falling back to "node {id}" when unlabeled
+    fn label(&self, node: usize) -> String {
+        self.labels.get(&node).cloned().unwrap_or_else(|| format!("node {}", node))
+    }
+
+    // Reverse lookup: find the node id for a given label
+    fn node_by_label(&self, label: &str) -> Option<usize> {
+        self.labels
+            .iter()
+            .find(|(_, candidate)| candidate.as_str() == label)
+            .map(|(&id, _)| id)
+    }
+
+    // Adds a weighted edge (unweighted callers can pass 1.0)
+    fn add_edge(&mut self, source: usize, target: usize, weight: f64) {
+        self.adj_list.entry(source).or_default().push((target, weight));
     }
 
-    // Load from edgelist: ignore weights
+    // Load from edgelist: parts[2] is an optional edge weight, defaulting to 1.0.
+    // Uses the adjacent `.idx` binary cache when it's newer than the source file,
+    // and (re)writes the cache on a miss.
     fn from_edgelist(path: &str) -> Self {
+        let cache_path = format!("{}.idx", path);
+
+        // Not collapsed into a single `if ... && let` chain: that needs edition 2024,
+        // and this crate doesn't pin one.
+        #[allow(clippy::collapsible_if)]
+        if Self::cache_is_fresh(path, &cache_path) {
+            if let Ok(graph) = Self::load_binary(&cache_path) {
+                return graph;
+            }
+        }
+
+        let graph = Self::parse_edgelist(path);
+        let _ = graph.save_binary(&cache_path); // best-effort: a stale/missing cache just costs a re-parse next time
+
+        graph
+    }
+
+    // Parse the plain-text edgelist format
+    fn parse_edgelist(path: &str) -> Self {
         let file = File::open(path).expect("Unable to open edgelist file");
         let reader = BufReader::new(file);
 
@@ -35,14 +135,87 @@ impl Graph {
             }
             let source: usize = parts[0].parse().unwrap();
             let target: usize = parts[1].parse().unwrap();
-            graph.add_edge(source, target);
+            let weight: f64 = parts.get(2).and_then(|w| w.parse().ok()).unwrap_or(1.0);
+            graph.add_edge(source, target, weight);
         }
 
         graph
     }
 
+    // True when `cache_path` exists and is at least as new as `source_path`
+    fn cache_is_fresh(source_path: &str, cache_path: &str) -> bool {
+        let source_modified = match fs::metadata(source_path).and_then(|m| m.modified()) {
+            Ok(modified) => modified,
+            Err(_) => return false,
+        };
+        let cache_modified = match fs::metadata(cache_path).and_then(|m| m.modified()) {
+            Ok(modified) => modified,
+            Err(_) => return false,
+        };
+
+        cache_modified >= source_modified
+    }
+
+    // Serialize the adjacency list to a compact binary blob:
+    // magic header, version byte, node count, then per node its id,
+    // neighbor count, and (neighbor id, weight) pairs, all little-endian.
+    fn save_binary<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+
+        writer.write_all(CACHE_MAGIC)?;
+        writer.write_all(&[CACHE_VERSION])?;
+        writer.write_u64::<LittleEndian>(self.adj_list.len() as u64)?;
+
+        for (&source, neighbors) in &self.adj_list {
+            writer.write_u64::<LittleEndian>(source as u64)?;
+            writer.write_u32::<LittleEndian>(neighbors.len() as u32)?;
+            for &(target, weight) in neighbors {
+                writer.write_u64::<LittleEndian>(target as u64)?;
+                writer.write_f64::<LittleEndian>(weight)?;
+            }
+        }
+
+        writer.flush()
+    }
+
+    // Load a graph previously written by save_binary
+    fn load_binary<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let mut reader = BufReader::new(file);
+
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != CACHE_MAGIC {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "not a graph binary cache"));
+        }
+
+        let mut version = [0u8; 1];
+        reader.read_exact(&mut version)?;
+        if version[0] != CACHE_VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "unsupported graph cache version"));
+        }
+
+        let node_count = reader.read_u64::<LittleEndian>()?;
+        let mut graph = Graph::new();
+
+        for _ in 0..node_count {
+            let source = reader.read_u64::<LittleEndian>()? as usize;
+            let neighbor_count = reader.read_u32::<LittleEndian>()?;
+            let mut neighbors = Vec::with_capacity(neighbor_count as usize);
+            for _ in 0..neighbor_count {
+                let target = reader.read_u64::<LittleEndian>()? as usize;
+                let weight = reader.read_f64::<LittleEndian>()?;
+                neighbors.push((target, weight));
+            }
+            graph.adj_list.insert(source, neighbors);
+        }
+
+        Ok(graph)
+    }
+
 
-    // BFS: find shortest path lengths from start
+    // BFS: find shortest path lengths (hop count) from start
     fn bfs(&self, start: usize) -> HashMap<usize, usize> {
         let mut distances = HashMap::new();
         let mut queue = VecDeque::new();
@@ -52,7 +225,7 @@ impl Graph {
 
         while let Some(node) = queue.pop_front() {
             if let Some(neighbors) = self.adj_list.get(&node) {
-                for &neighbor in neighbors {
+                for &(neighbor, _) in neighbors {
                     if !distances.contains_key(&neighbor) {
                         distances.insert(neighbor, distances[&node] + 1);
                         queue.push_back(neighbor);
@@ -63,9 +236,177 @@ impl Graph {
 
         distances
     }
+
+    // Dijkstra: find shortest weighted path lengths from start
+    fn dijkstra(&self, start: usize) -> HashMap<usize, f64> {
+        let mut distances = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        distances.insert(start, 0.0);
+        heap.push(Reverse((OrderedF64(0.0), start)));
+
+        while let Some(Reverse((OrderedF64(dist), node))) = heap.pop() {
+            if dist > distances[&node] {
+                continue; // stale heap entry, a shorter path was already found
+            }
+
+            if let Some(neighbors) = self.adj_list.get(&node) {
+                for &(neighbor, weight) in neighbors {
+                    let new_dist = dist + weight;
+                    if new_dist < *distances.get(&neighbor).unwrap_or(&f64::INFINITY) {
+                        distances.insert(neighbor, new_dist);
+                        heap.push(Reverse((OrderedF64(new_dist), neighbor)));
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+
+    // All node ids that appear as either a source or a target
+    fn all_nodes(&self) -> HashSet<usize> {
+        let mut nodes: HashSet<usize> = self.adj_list.keys().copied().collect();
+        for neighbors in self.adj_list.values() {
+            for &(target, _) in neighbors {
+                nodes.insert(target);
+            }
+        }
+        nodes
+    }
+
+    // Undirected adjacency: every edge u->v also makes v a neighbor of u
+    fn undirected_adjacency(&self) -> HashMap<usize, Vec<usize>> {
+        let mut undirected: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (&source, neighbors) in &self.adj_list {
+            for &(target, _) in neighbors {
+                undirected.entry(source).or_default().push(target);
+                undirected.entry(target).or_default().push(source);
+            }
+        }
+        undirected
+    }
+
+    // Connected components, treating every edge as undirected
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        let undirected = self.undirected_adjacency();
+        let mut visited: HashSet<usize> = HashSet::new();
+        let mut components = Vec::new();
+
+        for node in self.all_nodes() {
+            if visited.contains(&node) {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut queue = VecDeque::new();
+            visited.insert(node);
+            queue.push_back(node);
+
+            while let Some(current) = queue.pop_front() {
+                component.push(current);
+                if let Some(neighbors) = undirected.get(&current) {
+                    for &neighbor in neighbors {
+                        if visited.insert(neighbor) {
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+            }
+
+            components.push(component);
+        }
+
+        components
+    }
+
+    // Classify the graph as Eulerian circuit, Eulerian path, or neither
+    fn is_eulerian(&self) -> EulerKind {
+        let mut degree: HashMap<usize, usize> = HashMap::new();
+        for (&source, neighbors) in &self.adj_list {
+            for &(target, _) in neighbors {
+                *degree.entry(source).or_insert(0) += 1;
+                *degree.entry(target).or_insert(0) += 1;
+            }
+        }
+
+        // Isolated (degree-0) vertices don't affect traceability, so only
+        // components that actually contain an edge need to be connected.
+        let components_with_edges = self
+            .connected_components()
+            .into_iter()
+            .filter(|component| component.iter().any(|node| degree.get(node).unwrap_or(&0) > &0))
+            .count();
+        if components_with_edges > 1 {
+            return EulerKind::Neither;
+        }
+
+        let odd_degree_count = degree.values().filter(|&&d| d % 2 != 0).count();
+
+        match odd_degree_count {
+            0 => EulerKind::Circuit,
+            2 => EulerKind::Path,
+            _ => EulerKind::Neither,
+        }
+    }
+
+    // Betweenness centrality via Brandes' algorithm: for each source, BFS while
+    // tracking the shortest-path count sigma and predecessors, then accumulate
+    // each node's dependency delta by walking the BFS order in reverse
+    fn betweenness_centrality(&self) -> HashMap<usize, f64> {
+        let nodes: Vec<usize> = self.all_nodes().into_iter().collect();
+        let mut centrality: HashMap<usize, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+
+        for &s in &nodes {
+            let mut stack: Vec<usize> = Vec::new();
+            let mut predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+            let mut sigma: HashMap<usize, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+            let mut dist: HashMap<usize, i64> = HashMap::new();
+
+            sigma.insert(s, 1.0);
+            dist.insert(s, 0);
+
+            let mut queue = VecDeque::new();
+            queue.push_back(s);
+
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                if let Some(neighbors) = self.adj_list.get(&v) {
+                    for &(w, _) in neighbors {
+                        // w visited for the first time
+                        if !dist.contains_key(&w) {
+                            dist.insert(w, dist[&v] + 1);
+                            queue.push_back(w);
+                        }
+                        // shortest path to w goes through v
+                        if dist[&w] == dist[&v] + 1 {
+                            let sigma_v = sigma[&v];
+                            *sigma.get_mut(&w).unwrap() += sigma_v;
+                            predecessors.entry(w).or_default().push(v);
+                        }
+                    }
+                }
+            }
+
+            let mut delta: HashMap<usize, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+            while let Some(w) = stack.pop() {
+                if let Some(preds) = predecessors.get(&w) {
+                    for &v in preds {
+                        let contribution = (sigma[&v] / sigma[&w]) * (1.0 + delta[&w]);
+                        *delta.get_mut(&v).unwrap() += contribution;
+                    }
+                }
+                if w != s {
+                    *centrality.get_mut(&w).unwrap() += delta[&w];
+                }
+            }
+        }
+
+        centrality
+    }
 }
 
-// Generate random graph
+// Generate random graph (Erdos-Renyi style: uniformly random endpoints)
 fn generate_random_graph(num_nodes: usize, num_edges: usize) -> Graph {
     let mut graph = Graph::new();
     let mut rng = rand::thread_rng();
@@ -75,23 +416,117 @@ fn generate_random_graph(num_nodes: usize, num_edges: usize) -> Graph {
         let source = *nodes.choose(&mut rng).unwrap();
         let target = *nodes.choose(&mut rng).unwrap();
         if source != target {
-            graph.add_edge(source, target);
+            graph.add_edge(source, target, 1.0);
         }
     }
 
     graph
 }
 
-// Average shortest path length
+// Watts-Strogatz small-world graph: start from a ring lattice where each node
+// connects to its k nearest neighbors, then rewire each edge with probability
+// beta to a random target, avoiding self-loops and duplicate edges
+fn generate_watts_strogatz_graph(num_nodes: usize, k: usize, beta: f64) -> Graph {
+    let mut graph = Graph::new();
+    let mut rng = rand::thread_rng();
+    let nodes: Vec<usize> = (0..num_nodes).collect();
+
+    for &source in &nodes {
+        for step in 1..=(k / 2) {
+            let mut target = (source + step) % num_nodes;
+
+            if rand::random::<f64>() < beta {
+                loop {
+                    let candidate = *nodes.choose(&mut rng).unwrap();
+                    let is_duplicate = graph.adj_list.get(&source).is_some_and(|neighbors| {
+                        neighbors.iter().any(|&(neighbor, _)| neighbor == candidate)
+                    });
+                    if candidate != source && !is_duplicate {
+                        target = candidate;
+                        break;
+                    }
+                }
+            }
+
+            graph.add_edge(source, target, 1.0);
+        }
+    }
+
+    graph
+}
+
+// Barabasi-Albert scale-free graph: seed a small complete graph of m0 nodes, then
+// attach each new node with m edges to existing nodes chosen with probability
+// proportional to their current degree (sampled in O(1) via a weighted endpoint list)
+fn generate_barabasi_albert_graph(num_nodes: usize, m0: usize, m: usize) -> Graph {
+    let mut graph = Graph::new();
+    let mut rng = rand::thread_rng();
+
+    // Each edge endpoint is pushed once per edge it belongs to, so sampling
+    // uniformly from this list is equivalent to sampling proportional to degree.
+    let mut endpoints: Vec<usize> = Vec::new();
+
+    for source in 0..m0 {
+        for target in (source + 1)..m0 {
+            graph.add_edge(source, target, 1.0);
+            endpoints.push(source);
+            endpoints.push(target);
+        }
+    }
+
+    for new_node in m0..num_nodes {
+        let mut targets: HashSet<usize> = HashSet::new();
+        while targets.len() < m.min(new_node) {
+            targets.insert(*endpoints.choose(&mut rng).unwrap());
+        }
+
+        for &target in &targets {
+            graph.add_edge(new_node, target, 1.0);
+            endpoints.push(new_node);
+            endpoints.push(target);
+        }
+    }
+
+    graph
+}
+
+// Average shortest path length (hop count), BFS'd from every node in parallel via rayon
 fn average_path_length(graph: &Graph) -> f64 {
     let nodes: Vec<usize> = graph.adj_list.keys().copied().collect();
-    let mut total_distance = 0;
+
+    let (total_distance, path_count) = nodes
+        .into_par_iter()
+        .map(|node| {
+            let distances = graph.bfs(node);
+            let mut local_total = 0usize;
+            let mut local_count = 0usize;
+            for &d in distances.values() {
+                if d > 0 {
+                    local_total += d;
+                    local_count += 1;
+                }
+            }
+            (local_total, local_count)
+        })
+        .reduce(|| (0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
+
+    if path_count == 0 {
+        0.0
+    } else {
+        total_distance as f64 / path_count as f64
+    }
+}
+
+// Average shortest path length using edge weights instead of hop count
+fn average_path_length_weighted(graph: &Graph) -> f64 {
+    let nodes: Vec<usize> = graph.adj_list.keys().copied().collect();
+    let mut total_distance = 0.0;
     let mut path_count = 0;
 
     for &node in &nodes {
-        let distances = graph.bfs(node);
-        for &d in distances.values() {
-            if d > 0 {
+        let distances = graph.dijkstra(node);
+        for (&target, &d) in &distances {
+            if target != node {
                 total_distance += d;
                 path_count += 1;
             }
@@ -101,23 +536,79 @@ fn average_path_length(graph: &Graph) -> f64 {
     if path_count == 0 {
         0.0
     } else {
-        total_distance as f64 / path_count as f64
+        total_distance / path_count as f64
     }
 }
 
 fn main() {
     println!("Loading Congress graph...");
-    let congress_graph = Graph::from_edgelist("congress.edgelist");
+    let labels_path = "congress_labels.csv";
+    let congress_graph = if Path::new(labels_path).exists() {
+        Graph::with_labels("congress.edgelist", labels_path)
+    } else {
+        Graph::from_edgelist("congress.edgelist")
+    };
+
+    println!("Analyzing network structure...");
+    let components = congress_graph.connected_components();
+    println!("Connected components: {}", components.len());
+    match congress_graph.is_eulerian() {
+        EulerKind::Circuit => println!("Eulerian: circuit (traceable as a closed loop)"),
+        EulerKind::Path => println!("Eulerian: path (traceable start-to-finish, not closed)"),
+        EulerKind::Neither => println!("Eulerian: neither"),
+    }
 
     println!("Generating random graph...");
     let random_graph = generate_random_graph(475, 13289);
 
+    println!("Generating small-world graph...");
+    let small_world_graph = generate_watts_strogatz_graph(475, 56, 0.1);
+
+    println!("Generating scale-free graph...");
+    let scale_free_graph = generate_barabasi_albert_graph(475, 5, 28);
+
     println!("Calculating average path lengths...");
     let avg_congress = average_path_length(&congress_graph);
     let avg_random = average_path_length(&random_graph);
+    let avg_small_world = average_path_length(&small_world_graph);
+    let avg_scale_free = average_path_length(&scale_free_graph);
 
     println!("Average path length (Congress): {:.4}", avg_congress);
     println!("Average path length (Random): {:.4}", avg_random);
+    println!("Average path length (Small-world): {:.4}", avg_small_world);
+    println!("Average path length (Scale-free): {:.4}", avg_scale_free);
+
+    let avg_congress_weighted = average_path_length_weighted(&congress_graph);
+    println!(
+        "Average path length (Congress, weighted by interaction strength): {:.4}",
+        avg_congress_weighted
+    );
+
+    println!("Ranking members by betweenness centrality...");
+    let centrality = congress_graph.betweenness_centrality();
+    let mut ranked: Vec<(usize, f64)> = centrality.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    println!("Top 10 most central members:");
+    for &(node, score) in ranked.iter().take(10) {
+        println!("  {}: {:.4}", congress_graph.label(node), score);
+    }
+
+    // Demonstrate querying the distance between two named members directly
+    let (query_a, query_b) = ("Nancy Pelosi", "Mitch McConnell");
+    match (
+        congress_graph.node_by_label(query_a),
+        congress_graph.node_by_label(query_b),
+    ) {
+        (Some(id_a), Some(id_b)) => {
+            let distances = congress_graph.bfs(id_a);
+            match distances.get(&id_b) {
+                Some(&hops) => println!("Distance from {} to {}: {} hop(s)", query_a, query_b, hops),
+                None => println!("{} is unreachable from {}", query_b, query_a),
+            }
+        }
+        _ => println!("Skipping named distance query: \"{}\" and/or \"{}\" not found in labels", query_a, query_b),
+    }
 }
 
 #[cfg(test)]
@@ -127,21 +618,21 @@ mod tests {
     #[test]
     fn test_add_edge() {
         let mut graph = Graph::new();
-        graph.add_edge(1, 2);
-        graph.add_edge(1, 3);
+        graph.add_edge(1, 2, 1.0);
+        graph.add_edge(1, 3, 2.5);
 
-        assert_eq!(graph.adj_list.get(&1).unwrap(), &vec![2, 3]);
+        assert_eq!(graph.adj_list.get(&1).unwrap(), &vec![(2, 1.0), (3, 2.5)]);
     }
 
     #[test]
     fn test_bfs_simple() {
         let mut graph = Graph::new();
-        graph.add_edge(0, 1);
-        graph.add_edge(1, 2);
-        graph.add_edge(2, 3);
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(1, 2, 1.0);
+        graph.add_edge(2, 3, 1.0);
 
         let distances = graph.bfs(0);
-        
+
         assert_eq!(distances.get(&0), Some(&0));
         assert_eq!(distances.get(&1), Some(&1));
         assert_eq!(distances.get(&2), Some(&2));
@@ -151,8 +642,8 @@ mod tests {
     #[test]
     fn test_average_path_length_small_graph() {
         let mut graph = Graph::new();
-        graph.add_edge(0, 1);
-        graph.add_edge(1, 2);
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(1, 2, 1.0);
 
         let avg = average_path_length(&graph);
 
@@ -160,4 +651,154 @@ mod tests {
 
         assert!((avg - expected_avg).abs() == 0.0);
     }
+
+    #[test]
+    fn test_dijkstra_simple() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(1, 2, 2.0);
+        graph.add_edge(0, 2, 5.0);
+
+        let distances = graph.dijkstra(0);
+
+        assert_eq!(distances.get(&0), Some(&0.0));
+        assert_eq!(distances.get(&1), Some(&1.0));
+        assert_eq!(distances.get(&2), Some(&3.0));
+    }
+
+    #[test]
+    fn test_average_path_length_weighted_small_graph() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 2.0);
+        graph.add_edge(1, 2, 3.0);
+
+        let avg = average_path_length_weighted(&graph);
+
+        let expected_avg = (2.0 + 5.0 + 3.0) / 3.0;
+
+        assert!((avg - expected_avg).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_connected_components_disjoint() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(2, 3, 1.0);
+        graph.add_edge(3, 2, 1.0);
+
+        let mut components = graph.connected_components();
+        for component in components.iter_mut() {
+            component.sort();
+        }
+        components.sort();
+
+        assert_eq!(components, vec![vec![0, 1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_is_eulerian_circuit() {
+        // Triangle: every vertex has degree 2
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(1, 2, 1.0);
+        graph.add_edge(2, 0, 1.0);
+
+        assert_eq!(graph.is_eulerian(), EulerKind::Circuit);
+    }
+
+    #[test]
+    fn test_is_eulerian_path() {
+        // Path 0-1-2: endpoints have odd degree, middle has even degree
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(1, 2, 1.0);
+
+        assert_eq!(graph.is_eulerian(), EulerKind::Path);
+    }
+
+    #[test]
+    fn test_is_eulerian_disconnected_is_neither() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(2, 3, 1.0);
+
+        assert_eq!(graph.is_eulerian(), EulerKind::Neither);
+    }
+
+    #[test]
+    fn test_save_and_load_binary_roundtrip() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(1, 2, 2.5);
+        graph.add_edge(2, 0, 3.0);
+
+        let cache_path = std::env::temp_dir().join("congress_graph_test.idx");
+        graph.save_binary(&cache_path).expect("Unable to write binary cache");
+        let loaded = Graph::load_binary(&cache_path).expect("Unable to read binary cache");
+        fs::remove_file(&cache_path).expect("Unable to remove binary cache");
+
+        assert_eq!(loaded.adj_list, graph.adj_list);
+    }
+
+    #[test]
+    fn test_watts_strogatz_node_count_and_degree() {
+        let graph = generate_watts_strogatz_graph(20, 4, 0.0);
+
+        // With beta = 0.0 nothing is rewired, so it's the plain ring lattice:
+        // every node has exactly k/2 outgoing edges to its nearest neighbors.
+        for node in 0..20 {
+            assert_eq!(graph.adj_list.get(&node).unwrap().len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_barabasi_albert_preferential_attachment() {
+        let graph = generate_barabasi_albert_graph(10, 3, 2);
+
+        // Every node added after the seed clique should have exactly m outgoing edges
+        for node in 3..10 {
+            assert_eq!(graph.adj_list.get(&node).unwrap().len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_betweenness_centrality_path_graph() {
+        // Undirected path 0-1-2 (each edge stored both ways): node 1 sits on the
+        // shortest path for both the 0->2 and 2->0 traversals, the endpoints on neither
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 1.0);
+        graph.add_edge(1, 0, 1.0);
+        graph.add_edge(1, 2, 1.0);
+        graph.add_edge(2, 1, 1.0);
+
+        let centrality = graph.betweenness_centrality();
+
+        assert_eq!(centrality.get(&0), Some(&0.0));
+        assert_eq!(centrality.get(&1), Some(&2.0));
+        assert_eq!(centrality.get(&2), Some(&0.0));
+    }
+
+    #[test]
+    fn test_with_labels_and_node_by_label() {
+        let edgelist_path = std::env::temp_dir().join("congress_graph_test_labels.edgelist");
+        fs::write(&edgelist_path, "0 1\n1 2\n").expect("Unable to write edgelist fixture");
+
+        let labels_path = std::env::temp_dir().join("congress_graph_test_labels.csv");
+        fs::write(&labels_path, "0,Alice\n1,Bob\n2,Carol\n").expect("Unable to write labels fixture");
+
+        let graph = Graph::with_labels(
+            edgelist_path.to_str().unwrap(),
+            labels_path.to_str().unwrap(),
+        );
+
+        assert_eq!(graph.label(1), "Bob");
+        assert_eq!(graph.label(99), "node 99");
+        assert_eq!(graph.node_by_label("Carol"), Some(2));
+        assert_eq!(graph.node_by_label("Dave"), None);
+
+        fs::remove_file(&edgelist_path).expect("Unable to remove edgelist fixture");
+        fs::remove_file(format!("{}.idx", edgelist_path.to_str().unwrap()))
+            .expect("Unable to remove binary cache fixture");
+        fs::remove_file(&labels_path).expect("Unable to remove labels fixture");
+    }
 }
\ No newline at end of file